@@ -0,0 +1,92 @@
+use core::convert::TryFrom;
+use fixed::types::I64F64;
+
+use crate::{GeoHash, GeohashError};
+
+/// A validated `(lat, lon)` pair, in degrees.
+///
+/// Outside of this crate, constructing one through [`Coordinate::new`] (or
+/// the `TryFrom` impls below) is the only way to get a `Coordinate` value,
+/// so any function taking one can assume both fields are already
+/// range-checked. The fields themselves are `pub(crate)` rather than
+/// private - internal decode paths that already know a value is in range
+/// (e.g. the bounding-box decode behind [`GeoHash::try_as_coordinates`]) build one directly instead of
+/// re-validating through `new`. Use [`Coordinate::lat`]/[`Coordinate::lon`]
+/// to read the fields from outside the crate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinate {
+    pub(crate) lat: I64F64,
+    pub(crate) lon: I64F64,
+}
+
+impl Coordinate {
+    /// Build a `Coordinate`, checking `lat` is within `[-90, 90]` and `lon`
+    /// is within `[-180, 180]`.
+    pub fn new(lat: I64F64, lon: I64F64) -> Result<Coordinate, GeohashError> {
+        if lat < I64F64::from_num(-90) || lat > I64F64::from_num(90) {
+            return Err(GeohashError::BadLatitude(lat));
+        }
+        if lon < I64F64::from_num(-180) || lon > I64F64::from_num(180) {
+            return Err(GeohashError::BadLongitude(lon));
+        }
+        Ok(Coordinate { lat, lon })
+    }
+
+    /// This coordinate's latitude, in degrees.
+    pub fn lat(&self) -> I64F64 {
+        self.lat
+    }
+
+    /// This coordinate's longitude, in degrees.
+    pub fn lon(&self) -> I64F64 {
+        self.lon
+    }
+
+    /// Build a `Coordinate` from explicit `(lat, lon)` arguments - the same
+    /// order as [`crate::GeoHash::try_from_params`].
+    pub fn from_lat_lon(lat: I64F64, lon: I64F64) -> Result<Coordinate, GeohashError> {
+        Coordinate::new(lat, lon)
+    }
+
+    /// Build a `Coordinate` from explicit `(lon, lat)` arguments - the
+    /// GIS-standard order used by the `TryFrom<(I64F64, I64F64)>` impl below.
+    pub fn from_lon_lat(lon: I64F64, lat: I64F64) -> Result<Coordinate, GeohashError> {
+        Coordinate::new(lat, lon)
+    }
+}
+
+impl TryFrom<(I64F64, I64F64)> for Coordinate {
+    type Error = GeohashError;
+
+    /// Interprets the tuple in GIS-standard `(lon, lat)` order - see
+    /// [`Coordinate::from_lat_lon`]/[`Coordinate::from_lon_lat`] to be
+    /// explicit instead of relying on this convention.
+    fn try_from(value: (I64F64, I64F64)) -> Result<Coordinate, GeohashError> {
+        let (lon, lat) = value;
+        Coordinate::new(lat, lon)
+    }
+}
+
+impl TryFrom<(f64, f64)> for Coordinate {
+    type Error = GeohashError;
+
+    /// Interprets the tuple in GIS-standard `(lon, lat)` order, as the
+    /// `I64F64` tuple impl above does.
+    fn try_from(value: (f64, f64)) -> Result<Coordinate, GeohashError> {
+        let (lon, lat) = value;
+        Coordinate::new(I64F64::from_num(lat), I64F64::from_num(lon))
+    }
+}
+
+impl<const LEN: usize> GeoHash<LEN> {
+    /// Encode an already-validated [`Coordinate`] to a geohash of length `LEN`.
+    pub fn try_from_coordinate(coord: Coordinate) -> Result<GeoHash<LEN>, GeohashError> {
+        GeoHash::try_from_params(coord.lat, coord.lon)
+    }
+
+    /// Decode this cell's center into a [`Coordinate`].
+    pub fn as_coordinate(&self) -> Result<Coordinate, GeohashError> {
+        let (lon, lat, _, _) = self.try_as_coordinates()?;
+        Coordinate::new(lat, lon)
+    }
+}
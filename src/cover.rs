@@ -0,0 +1,349 @@
+//! Enumerate the geohash cells of a fixed length that cover a query region.
+
+use alloc::vec::Vec;
+use fixed::types::I64F64;
+
+use crate::distance::haversine;
+use crate::neighbors::Direction;
+use crate::trig;
+use crate::{GeoHash, GeohashError};
+
+/// The eight compass directions, clockwise from north (the order `Direction`
+/// declares them in).
+const ALL_DIRECTIONS: [Direction; 8] = [
+    Direction::N,
+    Direction::NE,
+    Direction::E,
+    Direction::SE,
+    Direction::S,
+    Direction::SW,
+    Direction::W,
+    Direction::NW,
+];
+
+/// Meters per degree of latitude, used to convert a search radius into degrees.
+const METERS_PER_DEGREE_LAT: I64F64 = I64F64::unwrapped_from_str("111320");
+
+fn deg_to_rad(deg: I64F64) -> I64F64 {
+    deg * trig::PI / I64F64::from_num(180)
+}
+
+/// The longitude/latitude span, in degrees, of a length-`LEN` geohash cell.
+///
+/// Each base32 character contributes 5 bits, alternating between longitude
+/// and latitude starting with longitude, so a `LEN`-character hash has
+/// `ceil(5*LEN/2)` longitude bits and `floor(5*LEN/2)` latitude bits.
+fn cell_span_deg<const LEN: usize>() -> (I64F64, I64F64) {
+    let total_bits = 5 * LEN;
+    let lon_bits = (total_bits + 1) / 2;
+    let lat_bits = total_bits / 2;
+    let lon_span = I64F64::from_num(360) / I64F64::from_num(1u128 << lon_bits);
+    let lat_span = I64F64::from_num(180) / I64F64::from_num(1u128 << lat_bits);
+    (lon_span, lat_span)
+}
+
+/// Number of `step`-sized hops needed for a cursor starting at the low edge
+/// to reach or pass `span_to_cover`, i.e. `ceil(span_to_cover / step)`.
+/// Comparing with a full-span margin (`cursor + step <= target`) instead of
+/// this would undercount by up to one hop and drop the far edge.
+fn steps_to_cover(span_to_cover: I64F64, step: I64F64) -> usize {
+    if span_to_cover <= I64F64::from_num(0) {
+        return 0;
+    }
+    let whole: i128 = (span_to_cover / step).to_num();
+    if I64F64::from_num(whole) * step >= span_to_cover {
+        whole as usize
+    } else {
+        whole as usize + 1
+    }
+}
+
+/// Sample points from `min` to `max` in `span`-sized steps, plus `max` itself
+/// explicitly - stepping by `span` alone can overshoot `max` before landing
+/// on it exactly, which used to silently skip the cell covering the far
+/// edge of the box.
+fn sample_axis(min: I64F64, max: I64F64, span: I64F64) -> Vec<I64F64> {
+    let mut samples = Vec::new();
+    let mut cursor = min;
+    while cursor < max {
+        samples.push(cursor);
+        cursor += span;
+    }
+    samples.push(max);
+    samples
+}
+
+/// Step one cell in `direction`, like [`GeoHash::neighbor`], but wrap longitude
+/// across the antimeridian and clamp latitude at the poles instead of
+/// returning `GeohashError::BadLatitude`/`BadLongitude`.
+fn step<const LEN: usize>(
+    gh: &GeoHash<LEN>,
+    direction: Direction,
+) -> Result<GeoHash<LEN>, GeohashError> {
+    let (lon, lat, lon_err, lat_err) = gh.try_as_coordinates()?;
+    let (dlat, dlng) = direction.to_tuple();
+    let two = I64F64::from_num(2);
+
+    let mut new_lon = lon + two * lon_err.abs() * dlng;
+    let mut new_lat = lat + two * lat_err.abs() * dlat;
+
+    if new_lon > I64F64::from_num(180) {
+        new_lon -= I64F64::from_num(360);
+    } else if new_lon < I64F64::from_num(-180) {
+        new_lon += I64F64::from_num(360);
+    }
+    new_lat = new_lat
+        .max(I64F64::from_num(-90))
+        .min(I64F64::from_num(90));
+
+    GeoHash::try_from_params(new_lat, new_lon)
+}
+
+impl<const LEN: usize> GeoHash<LEN> {
+    /// Return the minimal square block of length-`LEN` cells whose union
+    /// contains the circle of radius `radius_m` (in meters) around
+    /// `(lat, lon)`.
+    ///
+    /// Mirrors how Redis's `geohash_helper` picks a precision and scans a
+    /// neighbor block before filtering candidates.
+    ///
+    /// Returns `GeohashError::BoundingBoxTooLarge` without scanning if the
+    /// block would contain more than `max_cells` cells - a coarse `LEN`
+    /// combined with a large `radius_m` can otherwise blow up the side of
+    /// the block arbitrarily.
+    pub fn cover_radius(
+        lat: I64F64,
+        lon: I64F64,
+        radius_m: I64F64,
+        max_cells: usize,
+    ) -> Result<Vec<GeoHash<LEN>>, GeohashError> {
+        let center = GeoHash::<LEN>::try_from_params(lat, lon)?;
+
+        let (lon_span, lat_span) = cell_span_deg::<LEN>();
+        let (_, cos_lat) = trig::sin_cos(deg_to_rad(lat));
+        let meters_per_degree_lon = METERS_PER_DEGREE_LAT * cos_lat.abs();
+
+        let lat_span_m = lat_span * METERS_PER_DEGREE_LAT;
+        let lon_span_m = lon_span * meters_per_degree_lon;
+        let cell_span_m = lat_span_m.min(lon_span_m).max(I64F64::from_num(1));
+
+        // Smallest ring count whose rings alone span the query radius, so the
+        // block covers it wherever in the center cell `(lat, lon)` lies, via
+        // a single ceiling division rather than growing the ring count one
+        // hop at a time - a large `radius_m` (e.g. a unit mix-up passing
+        // meters where kilometers were meant) would otherwise do O(radius)
+        // work before `max_cells` is ever consulted below.
+        let rings = steps_to_cover(radius_m, cell_span_m);
+        let lon_columns = 1usize
+            .checked_shl(crate::quantize::axis_bits(LEN).0)
+            .unwrap_or(usize::MAX);
+
+        let side = 2usize.saturating_mul(rings).saturating_add(1);
+        if side.saturating_mul(side) > max_cells {
+            return Err(GeohashError::BoundingBoxTooLarge);
+        }
+
+        let mut sw = center.clone();
+        for _ in 0..rings {
+            sw = step(&sw, Direction::W)?;
+        }
+        for _ in 0..rings {
+            sw = step(&sw, Direction::S)?;
+        }
+
+        // Rows are capped at the number of longitude columns, so a row never
+        // wraps all the way round the antimeridian back onto its own cells;
+        // the only revisits left are at the poles, where whole rows clamp to
+        // the same latitude. An O(n) dedup against the last-seen column/row
+        // is therefore enough and we don't need an O(n^2) `cells.contains`
+        // scan.
+        let mut cells = Vec::with_capacity(side * side);
+        let mut row = sw;
+        let mut prev_row_start: Option<GeoHash<LEN>> = None;
+        for _ in 0..side {
+            if prev_row_start.as_ref() != Some(&row) {
+                prev_row_start = Some(row.clone());
+
+                let mut cell = row.clone();
+                let mut prev: Option<GeoHash<LEN>> = None;
+                for _ in 0..side.min(lon_columns) {
+                    if prev.as_ref() != Some(&cell) {
+                        cells.push(cell.clone());
+                    }
+                    prev = Some(cell.clone());
+                    cell = step(&cell, Direction::E)?;
+                }
+            }
+            row = step(&row, Direction::N)?;
+        }
+
+        Ok(cells)
+    }
+
+    /// Return every length-`LEN` cell intersecting the axis-aligned rectangle
+    /// `[min_lat, max_lat] x [min_lon, max_lon]`, analogous to MeiliSearch's
+    /// `_geoBoundingBox` filter.
+    ///
+    /// Returns `GeohashError::BoundingBoxTooLarge` without scanning if the
+    /// rectangle would contain more than `max_cells` cells.
+    pub fn cover_bbox(
+        min_lat: I64F64,
+        min_lon: I64F64,
+        max_lat: I64F64,
+        max_lon: I64F64,
+        max_cells: usize,
+    ) -> Result<Vec<GeoHash<LEN>>, GeohashError> {
+        if max_lat < min_lat {
+            return Err(GeohashError::BadBoundingBox);
+        }
+
+        let sw = GeoHash::<LEN>::try_from_params(min_lat, min_lon)?;
+        let (lon, lat, _, _) = sw.try_as_coordinates()?;
+
+        let (lon_span, lat_span) = cell_span_deg::<LEN>();
+
+        // How many cells to step east/north to reach or pass the NE corner.
+        // Using `ceil` here (rather than a `cursor + span <= max` loop) keeps
+        // this from needing a full extra span of margin before counting
+        // another column/row, which used to silently drop the NE corner cell
+        // whenever the box wasn't comfortably wider than several cells.
+        // Columns are capped at the number of longitude columns, so a box
+        // spanning all longitudes does not wrap back onto its first column.
+        let lon_columns = 1usize
+            .checked_shl(crate::quantize::axis_bits(LEN).0)
+            .unwrap_or(usize::MAX);
+        let cols = (steps_to_cover(max_lon - lon, lon_span) + 1).min(lon_columns);
+        let rows = steps_to_cover(max_lat - lat, lat_span) + 1;
+
+        if cols.saturating_mul(rows) > max_cells {
+            return Err(GeohashError::BoundingBoxTooLarge);
+        }
+
+        // As in `cover_radius`, with the column count capped the walk only
+        // revisits cells at the poles, so an O(n) dedup against the last-seen
+        // column/row is enough and we don't need an O(n^2) `cells.contains`
+        // scan.
+        let mut cells = Vec::with_capacity(cols * rows);
+        let mut row = sw;
+        let mut prev_row_start: Option<GeoHash<LEN>> = None;
+        for _ in 0..rows {
+            if prev_row_start.as_ref() != Some(&row) {
+                prev_row_start = Some(row.clone());
+
+                let mut cell = row.clone();
+                let mut prev: Option<GeoHash<LEN>> = None;
+                for _ in 0..cols {
+                    if prev.as_ref() != Some(&cell) {
+                        cells.push(cell.clone());
+                    }
+                    prev = Some(cell.clone());
+                    cell = step(&cell, Direction::E)?;
+                }
+            }
+            row = step(&row, Direction::N)?;
+        }
+
+        Ok(cells)
+    }
+
+    /// Return the length-`LEN` cells whose bounding box may contain a point
+    /// within `radius_m` meters of `(center_lat, center_lon)`, mirroring
+    /// Redis's `GEORADIUS`/Meilisearch's `_geoRadius`.
+    ///
+    /// Scanning just the center cell and its 3x3 neighborhood is only
+    /// sufficient when `LEN` is coarse enough that a cell's smaller dimension
+    /// exceeds `2 * radius_m`; when it isn't, this falls back to
+    /// [`GeoHash::cover_radius`] (capped at `max_cells`, as that function is)
+    /// so the result stays complete instead of silently covering too little
+    /// ground.
+    pub fn within_radius(
+        center_lat: I64F64,
+        center_lon: I64F64,
+        radius_m: I64F64,
+        max_cells: usize,
+    ) -> Result<Vec<GeoHash<LEN>>, GeohashError> {
+        let center = GeoHash::<LEN>::try_from_params(center_lat, center_lon)?;
+
+        let (lon_span, lat_span) = cell_span_deg::<LEN>();
+        let (_, cos_lat) = trig::sin_cos(deg_to_rad(center_lat));
+        let half_lat_m = lat_span / I64F64::from_num(2) * METERS_PER_DEGREE_LAT;
+        let half_lon_m =
+            lon_span / I64F64::from_num(2) * METERS_PER_DEGREE_LAT * cos_lat.abs();
+        let half_diagonal_m = trig::sqrt(half_lat_m * half_lat_m + half_lon_m * half_lon_m);
+
+        let cell_span_m = half_lat_m.min(half_lon_m) * I64F64::from_num(2);
+        let candidates = if cell_span_m > I64F64::from_num(2) * radius_m {
+            let mut candidates = Vec::with_capacity(9);
+            candidates.push(center.clone());
+            for direction in ALL_DIRECTIONS {
+                let neighbor = step(&center, direction)?;
+                if !candidates.contains(&neighbor) {
+                    candidates.push(neighbor);
+                }
+            }
+            candidates
+        } else {
+            GeoHash::<LEN>::cover_radius(center_lat, center_lon, radius_m, max_cells)?
+        };
+
+        // `candidates` is already duplicate-free: the 3x3 branch skips
+        // neighbors that collapse onto an already-collected cell at the
+        // poles, and `cover_radius` dedups its own scan.
+        let mut cells = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let (clon, clat, _, _) = candidate.try_as_coordinates()?;
+            let lower_bound = haversine(center_lat, center_lon, clat, clon) - half_diagonal_m;
+            if lower_bound <= radius_m {
+                cells.push(candidate);
+            }
+        }
+
+        Ok(cells)
+    }
+
+    /// Enumerate every length-`LEN` cell intersecting the bounding box, in
+    /// row-major order, porting the approach of ClickHouse's
+    /// `geohashesInBox`: sample a regular grid of points at the cell size and
+    /// encode each one, deduplicating adjacent identical hashes.
+    ///
+    /// Returns `GeohashError::BoundingBoxTooLarge` without scanning if the
+    /// estimated cell count exceeds `max_cells`.
+    pub fn cells_in_bbox(
+        min_lat: I64F64,
+        min_lon: I64F64,
+        max_lat: I64F64,
+        max_lon: I64F64,
+        max_cells: usize,
+    ) -> Result<Vec<GeoHash<LEN>>, GeohashError> {
+        if max_lat < min_lat || max_lon < min_lon {
+            return Err(GeohashError::BadBoundingBox);
+        }
+
+        let (lon_span, lat_span) = cell_span_deg::<LEN>();
+
+        // +2 rather than +1: one for the usual partial-cell remainder, one
+        // more for the explicit max-edge sample `sample_axis` always adds.
+        let approx_cols = ((max_lon - min_lon) / lon_span).to_num::<u128>() + 2;
+        let approx_rows = ((max_lat - min_lat) / lat_span).to_num::<u128>() + 2;
+        if approx_cols.saturating_mul(approx_rows) > max_cells as u128 {
+            return Err(GeohashError::BoundingBoxTooLarge);
+        }
+
+        let lats = sample_axis(min_lat, max_lat, lat_span);
+        let lons = sample_axis(min_lon, max_lon, lon_span);
+
+        let mut cells = Vec::new();
+        for lat in lats {
+            let mut prev: Option<GeoHash<LEN>> = None;
+            for &lon in &lons {
+                let cell = GeoHash::<LEN>::try_from_params(lat, lon)?;
+                if prev.as_ref() != Some(&cell) {
+                    cells.push(cell.clone());
+                }
+                prev = Some(cell);
+            }
+        }
+
+        Ok(cells)
+    }
+}
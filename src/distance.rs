@@ -0,0 +1,80 @@
+//! Great-circle distance between geohash cells.
+
+use fixed::types::I64F64;
+
+use crate::trig;
+use crate::{GeoHash, GeohashError};
+
+/// Mean Earth radius in meters, as used by the Haversine formula.
+const EARTH_RADIUS_METERS: I64F64 = I64F64::unwrapped_from_str("6372797.56");
+
+fn deg_to_rad(deg: I64F64) -> I64F64 {
+    deg * trig::PI / I64F64::from_num(180)
+}
+
+/// Haversine great-circle distance between two `(lat, lon)` points in degrees,
+/// in meters.
+pub fn haversine(lat1: I64F64, lon1: I64F64, lat2: I64F64, lon2: I64F64) -> I64F64 {
+    let phi1 = deg_to_rad(lat1);
+    let phi2 = deg_to_rad(lat2);
+    let d_phi = phi2 - phi1;
+    let d_lambda = deg_to_rad(lon2 - lon1);
+
+    let two = I64F64::from_num(2);
+    let (sin_half_d_phi, _) = trig::sin_cos(d_phi / two);
+    let (sin_half_d_lambda, _) = trig::sin_cos(d_lambda / two);
+    let (_, cos_phi1) = trig::sin_cos(phi1);
+    let (_, cos_phi2) = trig::sin_cos(phi2);
+
+    let a = sin_half_d_phi * sin_half_d_phi
+        + cos_phi1 * cos_phi2 * sin_half_d_lambda * sin_half_d_lambda;
+    let a = a.max(I64F64::from_num(0)).min(I64F64::from_num(1));
+
+    let c = two * trig::atan2(trig::sqrt(a), trig::sqrt(I64F64::from_num(1) - a));
+    EARTH_RADIUS_METERS * c
+}
+
+/// Cheap equirectangular-projection distance approximation between two
+/// `(lat, lon)` points in degrees, in meters.
+///
+/// Much cheaper than [`haversine`] since it only needs one `cos`, but only
+/// accurate over short distances - use it for rough ranking, not absolute
+/// distances.
+pub fn equirectangular(lat1: I64F64, lon1: I64F64, lat2: I64F64, lon2: I64F64) -> I64F64 {
+    let two = I64F64::from_num(2);
+    let (_, cos_mean_lat) = trig::sin_cos(deg_to_rad((lat1 + lat2) / two));
+
+    let x = deg_to_rad(lon2 - lon1) * cos_mean_lat;
+    let y = deg_to_rad(lat2 - lat1);
+
+    EARTH_RADIUS_METERS * trig::sqrt(x * x + y * y)
+}
+
+impl<const LEN: usize> GeoHash<LEN> {
+    /// Great-circle (Haversine) distance between the centers of two geohash
+    /// cells, in meters.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use geohash::GeoHash;
+    /// let a = GeoHash::<9>::try_from("ww8p1r4t8").unwrap();
+    /// let b = GeoHash::<9>::try_from("ww8p1r4t9").unwrap();
+    /// assert!(a.distance_to(&b).unwrap() < fixed::types::I64F64::from_num(100));
+    /// ```
+    pub fn distance_to(&self, other: &GeoHash<LEN>) -> Result<I64F64, GeohashError> {
+        let (lon1, lat1, _, _) = self.try_as_coordinates()?;
+        let (lon2, lat2, _, _) = other.try_as_coordinates()?;
+        Ok(haversine(lat1, lon1, lat2, lon2))
+    }
+
+    /// Cheap equirectangular-approximation distance between the centers of
+    /// two geohash cells, in meters - see [`equirectangular`] for when this
+    /// is (and isn't) accurate enough to use over [`GeoHash::distance_to`].
+    pub fn equirectangular_to(&self, other: &GeoHash<LEN>) -> Result<I64F64, GeohashError> {
+        let (lon1, lat1, _, _) = self.try_as_coordinates()?;
+        let (lon2, lat2, _, _) = other.try_as_coordinates()?;
+        Ok(equirectangular(lat1, lon1, lat2, lon2))
+    }
+}
@@ -1,7 +1,55 @@
+use core::fmt;
 use fixed::types::I64F64;
+
 #[derive(Debug)]
 pub enum GeohashError {
     InvalidHashCharacter(char),
     InvalidLen,
-    InvalidCoordinateRange(I64F64, I64F64),
+    /// Latitude outside of `[-90, 90]`.
+    BadLatitude(I64F64),
+    /// Longitude outside of `[-180, 180]`.
+    BadLongitude(I64F64),
+    /// A bounding box whose top latitude is below its bottom latitude, or
+    /// whose east longitude is west of its west longitude.
+    BadBoundingBox,
+    /// `5 * LEN` does not fit the target integer width, or the supplied
+    /// integer carries bits outside of the `5 * LEN` range it should occupy.
+    InvalidPrecision,
+    /// The input was not a well-formed RFC 5870 `geo:` URI.
+    InvalidGeoUri,
+    /// A bounding box scan would produce more cells than the caller-supplied cap.
+    BoundingBoxTooLarge,
 }
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeohashError::InvalidHashCharacter(c) => {
+                write!(f, "invalid character in geohash: {:?}", c)
+            }
+            GeohashError::InvalidLen => write!(f, "invalid geohash length"),
+            GeohashError::BadLatitude(lat) => {
+                write!(f, "latitude {} is outside of the range [-90, 90]", lat)
+            }
+            GeohashError::BadLongitude(lon) => {
+                write!(f, "longitude {} is outside of the range [-180, 180]", lon)
+            }
+            GeohashError::BadBoundingBox => {
+                write!(f, "bounding box maximum latitude or longitude is below its minimum")
+            }
+            GeohashError::InvalidPrecision => {
+                write!(f, "geohash length does not fit the requested integer width, or the integer has bits outside the geohash's range")
+            }
+            GeohashError::InvalidGeoUri => write!(f, "invalid geo: URI"),
+            GeohashError::BoundingBoxTooLarge => {
+                write!(f, "bounding box would produce more cells than the requested cap")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+impl std::error::Error for GeohashError {}
@@ -0,0 +1,92 @@
+//! Interop with RFC 5870 `geo:` URIs (`geo:lat,lon[,alt][;u=uncertainty]`).
+
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use fixed::types::I64F64;
+
+use crate::{GeoHash, GeohashError};
+
+/// Reject a `;crs=` parameter naming anything other than `wgs84`; `;u=` and
+/// any other parameter are accepted but ignored.
+fn check_params(params: &str) -> Result<(), GeohashError> {
+    for param in params.split(';').filter(|p| !p.is_empty()) {
+        if let Some(crs) = param.strip_prefix("crs=") {
+            if !crs.eq_ignore_ascii_case("wgs84") {
+                return Err(GeohashError::InvalidGeoUri);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Number of decimal digits needed so that rounding to that many places
+/// doesn't lose the precision `err` (a cell's half-width error) represents.
+fn decimal_digits_for_error(err: I64F64) -> usize {
+    if err <= 0 {
+        return 18;
+    }
+    let ten = I64F64::from_num(10);
+    let mut e = err;
+    let mut digits = 0usize;
+    while e < 1 && digits < 18 {
+        e *= ten;
+        digits += 1;
+    }
+    digits
+}
+
+impl<const LEN: usize> GeoHash<LEN> {
+    /// Parse a `geo:lat,lon[,alt][;param=value...]` URI, as defined by
+    /// RFC 5870, into a geohash of length `LEN`.
+    ///
+    /// The optional altitude component and any `;`-separated parameters
+    /// (such as `;u=<uncertainty>`) are accepted but ignored, except a
+    /// `;crs=` parameter naming something other than `wgs84`, which is
+    /// rejected.
+    pub fn try_from_geo_uri(uri: &str) -> Result<GeoHash<LEN>, GeohashError> {
+        let body = uri.strip_prefix("geo:").ok_or(GeohashError::InvalidGeoUri)?;
+        let mut sections = body.splitn(2, ';');
+        let coords = sections.next().unwrap_or(body);
+        if let Some(params) = sections.next() {
+            check_params(params)?;
+        }
+
+        let mut parts = coords.split(',');
+        let lat_str = parts.next().ok_or(GeohashError::InvalidGeoUri)?;
+        let lon_str = parts.next().ok_or(GeohashError::InvalidGeoUri)?;
+
+        let lat = lat_str
+            .trim()
+            .parse::<I64F64>()
+            .map_err(|_| GeohashError::InvalidGeoUri)?;
+        let lon = lon_str
+            .trim()
+            .parse::<I64F64>()
+            .map_err(|_| GeohashError::InvalidGeoUri)?;
+
+        GeoHash::try_from_params(lat, lon)
+    }
+
+    /// Format this cell's center as a `geo:lat,lon` URI per RFC 5870, with
+    /// enough fractional digits to round-trip the precision implied by `LEN`.
+    pub fn to_geo_uri(&self) -> String {
+        let (lon, lat, lon_err, lat_err) = self
+            .try_as_coordinates()
+            .expect("GeoHash can only be constructed from valid base32 chars; qed");
+        let digits = decimal_digits_for_error(lon_err.min(lat_err));
+        format!("geo:{:.*},{:.*}", digits, lat, digits, lon)
+    }
+}
+
+impl<const LEN: usize> TryFrom<(I64F64, I64F64)> for GeoHash<LEN> {
+    type Error = GeohashError;
+
+    /// Interprets the tuple in GIS-standard `(lon, lat)` order, matching
+    /// `geo-uri-rs`'s `TryFrom<(f64, f64)>` — note this is the *opposite*
+    /// argument order from [`GeoHash::try_from_params`]`(lat, lon)` above.
+    fn try_from(value: (I64F64, I64F64)) -> Result<Self, Self::Error> {
+        let (lon, lat) = value;
+        GeoHash::try_from_params(lat, lon)
+    }
+}
@@ -0,0 +1,39 @@
+//! GeoJSON serialization of a geohash cell (feature `geojson`).
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{GeoHash, GeohashError};
+
+impl<const LEN: usize> GeoHash<LEN> {
+    /// Render this cell as a GeoJSON `Feature` whose geometry is the cell's
+    /// bounding box polygon (SW, SE, NE, NW, SW again to close the ring), with
+    /// the geohash string and center coordinate in `properties` and a
+    /// top-level `bbox` member.
+    ///
+    /// Built with `core::fmt::Write` into a `String` rather than `serde`,
+    /// since the crate is `no_std`.
+    pub fn to_geojson(&self) -> Result<String, GeohashError> {
+        let (lon, lat, lon_err, lat_err) = self.try_as_coordinates()?;
+        let min_lon = lon - lon_err;
+        let max_lon = lon + lon_err;
+        let min_lat = lat - lat_err;
+        let max_lat = lat + lat_err;
+
+        let hash_str = core::str::from_utf8(self)
+            .expect("GeoHash can only be constructed from valid base32 chars; qed");
+
+        let mut out = String::new();
+        write!(
+            out,
+            "{{\"type\":\"Feature\",\"bbox\":[{min_lon},{min_lat},{max_lon},{max_lat}],\
+             \"geometry\":{{\"type\":\"Polygon\",\"coordinates\":[[\
+             [{min_lon},{min_lat}],[{max_lon},{min_lat}],[{max_lon},{max_lat}],\
+             [{min_lon},{max_lat}],[{min_lon},{min_lat}]]]}},\
+             \"properties\":{{\"geohash\":\"{hash_str}\",\"center\":[{lon},{lat}]}}}}",
+        )
+        .expect("writing to a String cannot fail");
+
+        Ok(out)
+    }
+}
@@ -38,18 +38,11 @@ use core::convert::TryFrom;
 use codec::{Decode, Encode, MaxEncodedLen};
 use fixed::types::I64F64;
 
+pub use crate::coordinate::Coordinate;
 pub use crate::error::GeohashError;
 pub use crate::neighbors::{Direction, Neighbors};
 
-#[derive(Debug)]
-struct Coordinate
-{
-    pub lon: I64F64,
-    pub lat: I64F64,
-}
-
-struct Rectangle
-{
+struct Rectangle {
     min: Coordinate,
     max: Coordinate,
 }
@@ -137,49 +130,8 @@ impl<const LEN: usize> GeoHash<LEN> {
     /// assert_eq!(geohash_string, GeoHash::try_from("9q60y60rhs").unwrap());
     /// ```
     pub fn try_from_params(lat: I64F64, lon: I64F64) -> Result<GeoHash<LEN>, GeohashError> {
-        let mut out = [0u8; LEN];
-
-        let mut bits_total: i8 = 0;
-        let mut hash_value: usize = 0;
-        let mut max_lat = I64F64::from_num(90);
-        let mut min_lat = I64F64::from_num(-90);
-        let mut max_lon = I64F64::from_num(180);
-        let mut min_lon = I64F64::from_num(-180);
-
-        if lon < min_lon || lon > max_lon || lat < min_lat || lat > max_lat {
-            return Err(GeohashError::InvalidCoordinateRange(lon, lat));
-        }
-
-        let two = I64F64::from_num(2);
-        for i in 0..out.len() {
-            for _ in 0..5 {
-                if bits_total % 2 == 0 {
-                    let mid = (max_lon + min_lon) / two;
-                    if lon > mid {
-                        hash_value = (hash_value << 1) + 1usize;
-                        min_lon = mid;
-                    } else {
-                        hash_value <<= 1;
-                        max_lon = mid;
-                    }
-                } else {
-                    let mid = (max_lat + min_lat) / two;
-                    if lat > mid {
-                        hash_value = (hash_value << 1) + 1usize;
-                        min_lat = mid;
-                    } else {
-                        hash_value <<= 1;
-                        max_lat = mid;
-                    }
-                }
-                bits_total += 1;
-            }
-
-            let code: char = BASE32_CODES[hash_value];
-            out[i] = code as u8;
-            hash_value = 0;
-        }
-        Ok(GeoHash(out))
+        Coordinate::new(lat, lon)?;
+        crate::quantize::encode(lat, lon)
     }
 
     /// Decode geohash string into latitude, longitude
@@ -194,42 +146,7 @@ impl<const LEN: usize> GeoHash<LEN> {
     /// * min_lon
     /// * max_lon
     fn decode_bbox(&self) -> Result<Rectangle, GeohashError> {
-        let mut is_lon = true;
-        let mut max_lat = I64F64::from_num(90);
-        let mut min_lat = I64F64::from_num(-90);
-        let mut max_lon = I64F64::from_num(180);
-        let mut min_lon = I64F64::from_num(-180);
-        let mut mid: I64F64;
-        let mut hash_value: usize;
-
-        let two = I64F64::from_num(2);
-
-        for c in self.iter() {
-            hash_value = hash_value_of_char(*c as char)?;
-
-            for bs in 0..5 {
-                let bit = (hash_value >> (4 - bs)) & 1usize;
-                if is_lon {
-                    mid = (max_lon + min_lon) / two;
-
-                    if bit == 1 {
-                        min_lon = mid;
-                    } else {
-                        max_lon = mid;
-                    }
-                } else {
-                    mid = (max_lat + min_lat) / two;
-
-                    if bit == 1 {
-                        min_lat = mid;
-                    } else {
-                        max_lat = mid;
-                    }
-                }
-                is_lon = !is_lon;
-            }
-        }
-
+        let (min_lon, max_lon, min_lat, max_lat) = crate::quantize::decode(self)?;
         Ok(Rectangle {
             min: Coordinate {
                 lon: min_lon,
@@ -363,5 +280,171 @@ fn hash_value_of_char(c: char) -> Result<usize, GeohashError> {
     Err(GeohashError::InvalidHashCharacter(c))
 }
 
+mod coordinate;
+mod cover;
+mod distance;
 mod error;
+mod geo_uri;
+#[cfg(feature = "geojson")]
+mod geojson;
+mod morton;
 mod neighbors;
+mod quantize;
+mod trig;
+
+pub use crate::distance::{equirectangular, haversine};
+
+/// Reference bit-by-bit implementation of `try_from_params`/`decode_bbox`,
+/// kept around purely so a test can check the Morton-based fast path in
+/// [`quantize`] agrees with it on random input.
+#[cfg(test)]
+mod bitwise_reference {
+    use super::{hash_value_of_char, Coordinate, GeoHash, GeohashError, Rectangle, BASE32_CODES};
+    use fixed::types::I64F64;
+
+    pub(super) fn try_from_params<const LEN: usize>(
+        lat: I64F64,
+        lon: I64F64,
+    ) -> Result<GeoHash<LEN>, GeohashError> {
+        Coordinate::new(lat, lon)?;
+
+        let mut out = [0u8; LEN];
+        let mut bits_total: i8 = 0;
+        let mut hash_value: usize = 0;
+        let mut max_lat = I64F64::from_num(90);
+        let mut min_lat = I64F64::from_num(-90);
+        let mut max_lon = I64F64::from_num(180);
+        let mut min_lon = I64F64::from_num(-180);
+
+        let two = I64F64::from_num(2);
+        for slot in out.iter_mut() {
+            for _ in 0..5 {
+                if bits_total % 2 == 0 {
+                    let mid = (max_lon + min_lon) / two;
+                    if lon > mid {
+                        hash_value = (hash_value << 1) + 1usize;
+                        min_lon = mid;
+                    } else {
+                        hash_value <<= 1;
+                        max_lon = mid;
+                    }
+                } else {
+                    let mid = (max_lat + min_lat) / two;
+                    if lat > mid {
+                        hash_value = (hash_value << 1) + 1usize;
+                        min_lat = mid;
+                    } else {
+                        hash_value <<= 1;
+                        max_lat = mid;
+                    }
+                }
+                bits_total += 1;
+            }
+            *slot = BASE32_CODES[hash_value] as u8;
+            hash_value = 0;
+        }
+        Ok(GeoHash(out))
+    }
+
+    pub(super) fn decode_bbox<const LEN: usize>(gh: &GeoHash<LEN>) -> Result<Rectangle, GeohashError> {
+        let mut is_lon = true;
+        let mut max_lat = I64F64::from_num(90);
+        let mut min_lat = I64F64::from_num(-90);
+        let mut max_lon = I64F64::from_num(180);
+        let mut min_lon = I64F64::from_num(-180);
+        let two = I64F64::from_num(2);
+
+        for c in gh.iter() {
+            let hash_value = hash_value_of_char(*c as char)?;
+            for bs in 0..5 {
+                let bit = (hash_value >> (4 - bs)) & 1usize;
+                if is_lon {
+                    let mid = (max_lon + min_lon) / two;
+                    if bit == 1 {
+                        min_lon = mid;
+                    } else {
+                        max_lon = mid;
+                    }
+                } else {
+                    let mid = (max_lat + min_lat) / two;
+                    if bit == 1 {
+                        min_lat = mid;
+                    } else {
+                        max_lat = mid;
+                    }
+                }
+                is_lon = !is_lon;
+            }
+        }
+
+        Ok(Rectangle {
+            min: Coordinate {
+                lon: min_lon,
+                lat: min_lat,
+            },
+            max: Coordinate {
+                lon: max_lon,
+                lat: max_lat,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bitwise_reference;
+    use super::GeoHash;
+    use fixed::types::I64F64;
+
+    /// Minimal xorshift PRNG so the property test below is reproducible
+    /// without pulling in a `rand` dependency.
+    struct XorShift64(u64);
+
+    impl XorShift64 {
+        fn next_u64(&mut self) -> u64 {
+            let mut x = self.0;
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            self.0 = x;
+            x
+        }
+
+        fn next_lat(&mut self) -> I64F64 {
+            let millionths = (self.next_u64() % 180_000_000) as i64 - 90_000_000;
+            I64F64::from_num(millionths) / I64F64::from_num(1_000_000)
+        }
+
+        fn next_lon(&mut self) -> I64F64 {
+            let millionths = (self.next_u64() % 360_000_000) as i64 - 180_000_000;
+            I64F64::from_num(millionths) / I64F64::from_num(1_000_000)
+        }
+    }
+
+    fn check_matches_bitwise<const LEN: usize>(rng: &mut XorShift64) {
+        for _ in 0..200 {
+            let lat = rng.next_lat();
+            let lon = rng.next_lon();
+
+            let fast = GeoHash::<LEN>::try_from_params(lat, lon).unwrap();
+            let reference = bitwise_reference::try_from_params::<LEN>(lat, lon).unwrap();
+            assert_eq!(fast, reference, "encode mismatch at lat={:?} lon={:?}", lat, lon);
+
+            let fast_bbox = fast.try_as_coordinates().unwrap();
+            let reference_rect = bitwise_reference::decode_bbox(&reference).unwrap();
+            let two = I64F64::from_num(2);
+            let reference_lon = (reference_rect.min.lon + reference_rect.max.lon) / two;
+            let reference_lat = (reference_rect.min.lat + reference_rect.max.lat) / two;
+            assert_eq!(fast_bbox.0, reference_lon);
+            assert_eq!(fast_bbox.1, reference_lat);
+        }
+    }
+
+    #[test]
+    fn morton_fast_path_matches_bitwise_reference() {
+        let mut rng = XorShift64(0x2545_F491_4F6C_DD1D);
+        check_matches_bitwise::<5>(&mut rng);
+        check_matches_bitwise::<9>(&mut rng);
+        check_matches_bitwise::<10>(&mut rng);
+    }
+}
@@ -0,0 +1,70 @@
+//! 64-bit Morton (Z-order) integer representation of a geohash cell.
+//!
+//! Each base32 character already packs 5 bits alternating between longitude
+//! and latitude (see [`GeoHash::try_from_params`]), so the base32 string and
+//! the interleaved integer are two encodings of the same bit sequence: the
+//! integer form is just those bits read as one big-endian number.
+
+use crate::{hash_value_of_char, GeoHash, GeohashError, BASE32_CODES};
+
+impl<const LEN: usize> GeoHash<LEN> {
+    /// Pack this cell's `5 * LEN` bits into a `u64` Morton code, longitude on
+    /// the even bit positions and latitude on the odd ones, counting from the
+    /// most significant of the `5 * LEN` bits (matching the interleaving
+    /// order `try_from_params` already produces).
+    ///
+    /// Only defined for `LEN <= 12` (`5 * LEN <= 64`); returns
+    /// `GeohashError::InvalidPrecision` otherwise.
+    pub fn try_to_u64(&self) -> Result<u64, GeohashError> {
+        if 5 * LEN > 64 {
+            return Err(GeohashError::InvalidPrecision);
+        }
+
+        let mut bits: u64 = 0;
+        for c in self.iter() {
+            let value = hash_value_of_char(*c as char)? as u64;
+            bits = (bits << 5) | value;
+        }
+        Ok(bits)
+    }
+
+    /// Reconstruct a `GeoHash<LEN>` from a Morton code produced by
+    /// [`GeoHash::try_to_u64`].
+    ///
+    /// `bits` must not carry any set bit at or above position `5 * LEN`.
+    pub fn from_interleaved(bits: u64) -> Result<GeoHash<LEN>, GeohashError> {
+        let total_bits = 5 * LEN;
+        if total_bits > 64 {
+            return Err(GeohashError::InvalidPrecision);
+        }
+        if total_bits < 64 && (bits >> total_bits) != 0 {
+            return Err(GeohashError::InvalidPrecision);
+        }
+
+        let mut out = [0u8; LEN];
+        for (i, slot) in out.iter_mut().enumerate() {
+            let shift = total_bits - 5 * (i + 1);
+            let value = ((bits >> shift) & 0b11111) as usize;
+            *slot = BASE32_CODES[value] as u8;
+        }
+        Ok(GeoHash(out))
+    }
+
+    /// The half-open integer range `[min, max)` this cell occupies once its
+    /// `5 * LEN` bits are left-aligned into a full 64-bit Morton code.
+    ///
+    /// Comparing a full-precision point's 64-bit code against this range
+    /// answers "is point X inside cell Y" with a single integer comparison.
+    pub fn interleaved_range(&self) -> Result<(u64, u64), GeohashError> {
+        let total_bits = 5 * LEN;
+        if total_bits == 0 || total_bits > 64 {
+            return Err(GeohashError::InvalidPrecision);
+        }
+
+        let value = self.try_to_u64()?;
+        let shift = 64 - total_bits;
+        let min = value << shift;
+        let max = min + (1u64 << shift);
+        Ok((min, max))
+    }
+}
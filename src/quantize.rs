@@ -0,0 +1,134 @@
+//! Branchless-ish encode/decode via integer bit-interleaving (Morton code).
+//!
+//! `try_from_params`/`decode_bbox` used to walk the `5 * LEN` bits one at a
+//! time, each step doing an `I64F64` midpoint subdivision. Here we instead
+//! quantize longitude and latitude into plain integers once, interleave their
+//! bits (longitude on the even positions, latitude on the odd ones, counting
+//! from the most significant bit), and slice the result into base32
+//! characters, trading repeated fixed-point comparisons for cheap integer
+//! shifts.
+
+use fixed::types::I64F64;
+
+use crate::{hash_value_of_char, GeoHash, GeohashError, BASE32_CODES};
+
+/// Quantize `value` (within `[min, max]`) into a `bits`-wide unsigned integer,
+/// clamping at the edges so `value == max` doesn't overflow.
+fn quantize_axis(value: I64F64, min: I64F64, max: I64F64, bits: u32) -> u64 {
+    let span = max - min;
+    let frac = (value - min) / span;
+    let scale = I64F64::from_num(1u64 << bits);
+    let scaled: i128 = (frac * scale).to_num();
+    let max_q = (1i128 << bits) - 1;
+    scaled.clamp(0, max_q) as u64
+}
+
+/// Inverse of [`quantize_axis`]: the `[low, high)` sub-interval of `[min, max]`
+/// that quantized value `q` represents.
+fn dequantize_axis(q: u64, min: I64F64, max: I64F64, bits: u32) -> (I64F64, I64F64) {
+    let span = max - min;
+    let cell = span / I64F64::from_num(1u64 << bits);
+    let low = min + I64F64::from_num(q) * cell;
+    (low, low + cell)
+}
+
+/// Interleave `lon_q` (`lon_bits` wide) and `lat_q` (`lat_bits` wide) into a
+/// single Morton code, longitude first (matching the bit order
+/// `try_from_params` has always produced: longitude, then latitude,
+/// alternating from the most to the least significant bit).
+fn interleave(lon_q: u64, lon_bits: u32, lat_q: u64, lat_bits: u32) -> u128 {
+    let mut code: u128 = 0;
+    for j in 0..lon_bits.max(lat_bits) {
+        if j < lon_bits {
+            let bit = (lon_q >> (lon_bits - 1 - j)) & 1;
+            code = (code << 1) | bit as u128;
+        }
+        if j < lat_bits {
+            let bit = (lat_q >> (lat_bits - 1 - j)) & 1;
+            code = (code << 1) | bit as u128;
+        }
+    }
+    code
+}
+
+/// Inverse of [`interleave`].
+fn deinterleave(code: u128, lon_bits: u32, lat_bits: u32) -> (u64, u64) {
+    let total_bits = lon_bits + lat_bits;
+    let mut remaining = total_bits;
+    let mut lon_q: u64 = 0;
+    let mut lat_q: u64 = 0;
+    for j in 0..lon_bits.max(lat_bits) {
+        if j < lon_bits {
+            remaining -= 1;
+            let bit = (code >> remaining) & 1;
+            lon_q = (lon_q << 1) | bit as u64;
+        }
+        if j < lat_bits {
+            remaining -= 1;
+            let bit = (code >> remaining) & 1;
+            lat_q = (lat_q << 1) | bit as u64;
+        }
+    }
+    (lon_q, lat_q)
+}
+
+/// `(longitude bits, latitude bits)` for a length-`LEN` geohash: each base32
+/// character is 5 bits, alternating lon/lat starting with longitude.
+pub(crate) fn axis_bits(len: usize) -> (u32, u32) {
+    let total_bits = 5 * len as u32;
+    ((total_bits + 1) / 2, total_bits / 2)
+}
+
+fn code_to_geohash<const LEN: usize>(code: u128, total_bits: u32) -> GeoHash<LEN> {
+    let mut out = [0u8; LEN];
+    for (i, slot) in out.iter_mut().enumerate() {
+        let shift = total_bits - 5 * (i as u32 + 1);
+        let value = ((code >> shift) & 0b11111) as usize;
+        *slot = BASE32_CODES[value] as u8;
+    }
+    GeoHash(out)
+}
+
+fn geohash_to_code<const LEN: usize>(gh: &GeoHash<LEN>) -> Result<u128, GeohashError> {
+    let mut code: u128 = 0;
+    for c in gh.iter() {
+        let value = hash_value_of_char(*c as char)? as u128;
+        code = (code << 5) | value;
+    }
+    Ok(code)
+}
+
+/// The interleaved code is packed into a `u128`, so only `LEN <= 25`
+/// (`5 * LEN <= 128`) fits; above that, bits would silently fall off the top
+/// of `code` in [`interleave`]/[`code_to_geohash`] instead of erroring.
+fn check_len(len: usize) -> Result<(), GeohashError> {
+    if 5 * len > 128 {
+        return Err(GeohashError::InvalidPrecision);
+    }
+    Ok(())
+}
+
+pub(crate) fn encode<const LEN: usize>(
+    lat: I64F64,
+    lon: I64F64,
+) -> Result<GeoHash<LEN>, GeohashError> {
+    check_len(LEN)?;
+    let (lon_bits, lat_bits) = axis_bits(LEN);
+    let lon_q = quantize_axis(lon, I64F64::from_num(-180), I64F64::from_num(180), lon_bits);
+    let lat_q = quantize_axis(lat, I64F64::from_num(-90), I64F64::from_num(90), lat_bits);
+    let code = interleave(lon_q, lon_bits, lat_q, lat_bits);
+    Ok(code_to_geohash(code, lon_bits + lat_bits))
+}
+
+/// `(min_lon, max_lon, min_lat, max_lat)` bounding box of `gh`.
+pub(crate) fn decode<const LEN: usize>(
+    gh: &GeoHash<LEN>,
+) -> Result<(I64F64, I64F64, I64F64, I64F64), GeohashError> {
+    check_len(LEN)?;
+    let (lon_bits, lat_bits) = axis_bits(LEN);
+    let code = geohash_to_code(gh)?;
+    let (lon_q, lat_q) = deinterleave(code, lon_bits, lat_bits);
+    let (min_lon, max_lon) = dequantize_axis(lon_q, I64F64::from_num(-180), I64F64::from_num(180), lon_bits);
+    let (min_lat, max_lat) = dequantize_axis(lat_q, I64F64::from_num(-90), I64F64::from_num(90), lat_bits);
+    Ok((min_lon, max_lon, min_lat, max_lat))
+}
@@ -0,0 +1,160 @@
+//! Fixed-point trigonometry for `I64F64`, implemented with the CORDIC algorithm.
+//!
+//! The crate is `no_std` and cannot rely on `f64::sin`/`libm`, so the handful of
+//! trig/sqrt primitives needed by the distance calculations in [`crate::distance`]
+//! are implemented here from scratch.
+
+use fixed::types::I64F64;
+
+const ITERATIONS: usize = 32;
+
+/// `atan(2^-i)` for `i` in `0..ITERATIONS`, used by the CORDIC rotation/vectoring loops.
+const ATAN_TABLE: [I64F64; ITERATIONS] = [
+    I64F64::unwrapped_from_str("0.7853981633974483"),
+    I64F64::unwrapped_from_str("0.4636476090008061"),
+    I64F64::unwrapped_from_str("0.24497866312686414"),
+    I64F64::unwrapped_from_str("0.12435499454676144"),
+    I64F64::unwrapped_from_str("0.06241880999595735"),
+    I64F64::unwrapped_from_str("0.031239833430268277"),
+    I64F64::unwrapped_from_str("0.015623728620476831"),
+    I64F64::unwrapped_from_str("0.007812341060101111"),
+    I64F64::unwrapped_from_str("0.0039062301319669718"),
+    I64F64::unwrapped_from_str("0.0019531225164788188"),
+    I64F64::unwrapped_from_str("0.0009765621895593195"),
+    I64F64::unwrapped_from_str("0.0004882812111948983"),
+    I64F64::unwrapped_from_str("0.00024414062014936177"),
+    I64F64::unwrapped_from_str("0.00012207031189367021"),
+    I64F64::unwrapped_from_str("0.00006103515617420877"),
+    I64F64::unwrapped_from_str("0.000030517578115526096"),
+    I64F64::unwrapped_from_str("0.000015258789061315762"),
+    I64F64::unwrapped_from_str("0.00000762939453110197"),
+    I64F64::unwrapped_from_str("0.000003814697265606496"),
+    I64F64::unwrapped_from_str("0.000001907348632810187"),
+    I64F64::unwrapped_from_str("0.0000009536743164059608"),
+    I64F64::unwrapped_from_str("0.00000047683715820308884"),
+    I64F64::unwrapped_from_str("0.00000023841857910155797"),
+    I64F64::unwrapped_from_str("0.00000011920928955078068"),
+    I64F64::unwrapped_from_str("0.00000005960464477539055"),
+    I64F64::unwrapped_from_str("0.000000029802322387695303"),
+    I64F64::unwrapped_from_str("0.000000014901161193847655"),
+    I64F64::unwrapped_from_str("0.000000007450580596923828"),
+    I64F64::unwrapped_from_str("0.000000003725290298461914"),
+    I64F64::unwrapped_from_str("0.000000001862645149230957"),
+    I64F64::unwrapped_from_str("0.0000000009313225746154785"),
+    I64F64::unwrapped_from_str("0.0000000004656612873077393"),
+];
+
+/// Product of `cos(atan(2^-i))` over all CORDIC iterations; starting the
+/// initial vector at this length instead of 1 means the rotation loop doesn't
+/// need a separate scaling pass afterwards.
+const CORDIC_GAIN: I64F64 = I64F64::unwrapped_from_str("0.6072529350088814");
+
+pub const PI: I64F64 = I64F64::unwrapped_from_str("3.14159265358979311600");
+pub const HALF_PI: I64F64 = I64F64::unwrapped_from_str("1.57079632679489655800");
+pub const TWO_PI: I64F64 = I64F64::unwrapped_from_str("6.28318530717958623200");
+
+/// Reduce an angle in radians to `(-PI, PI]`.
+fn reduce_angle(mut theta: I64F64) -> I64F64 {
+    while theta > PI {
+        theta -= TWO_PI;
+    }
+    while theta <= -PI {
+        theta += TWO_PI;
+    }
+    theta
+}
+
+/// Simultaneous sine and cosine of an angle in radians, via CORDIC rotation mode.
+///
+/// Returns `(sin, cos)`.
+pub fn sin_cos(theta: I64F64) -> (I64F64, I64F64) {
+    let theta = reduce_angle(theta);
+
+    // The rotation loop only converges for angles within [-PI/2, PI/2]; fold the
+    // rest of the circle in using `sin/cos(theta) = -sin/cos(theta -/+ PI)`.
+    let (theta, negate) = if theta > HALF_PI {
+        (theta - PI, true)
+    } else if theta < -HALF_PI {
+        (theta + PI, true)
+    } else {
+        (theta, false)
+    };
+
+    let mut x = CORDIC_GAIN;
+    let mut y = I64F64::from_num(0);
+    let mut z = theta;
+
+    for i in 0..ITERATIONS {
+        let shift = i as u32;
+        let d = if z >= 0 {
+            I64F64::from_num(1)
+        } else {
+            I64F64::from_num(-1)
+        };
+        let x_new = x - d * (y >> shift);
+        let y_new = y + d * (x >> shift);
+        z -= d * ATAN_TABLE[i];
+        x = x_new;
+        y = y_new;
+    }
+
+    if negate {
+        (-y, -x)
+    } else {
+        (y, x)
+    }
+}
+
+/// `atan2(y, x)` in radians, via CORDIC vectoring mode.
+pub fn atan2(y: I64F64, x: I64F64) -> I64F64 {
+    if x == 0 && y == 0 {
+        return I64F64::from_num(0);
+    }
+
+    let (mut x, mut y, mut z) = if x < 0 {
+        if y >= 0 {
+            (-x, -y, PI)
+        } else {
+            (-x, -y, -PI)
+        }
+    } else {
+        (x, y, I64F64::from_num(0))
+    };
+
+    for i in 0..ITERATIONS {
+        let shift = i as u32;
+        let d = if y < 0 {
+            I64F64::from_num(1)
+        } else {
+            I64F64::from_num(-1)
+        };
+        let x_new = x - d * (y >> shift);
+        let y_new = y + d * (x >> shift);
+        z -= d * ATAN_TABLE[i];
+        x = x_new;
+        y = y_new;
+    }
+
+    z
+}
+
+/// Non-negative square root via Newton-Raphson iteration, starting from `x`
+/// itself (or 1 when `x < 1`).
+pub fn sqrt(x: I64F64) -> I64F64 {
+    if x <= 0 {
+        return I64F64::from_num(0);
+    }
+    if x == 1 {
+        return x;
+    }
+
+    // Newton-Raphson for `sqrt` converges monotonically from any positive seed,
+    // so a crude starting guess just costs a few extra iterations.
+    let mut guess = if x > 1 { x } else { I64F64::from_num(1) };
+
+    let two = I64F64::from_num(2);
+    for _ in 0..60 {
+        guess = (guess + x / guess) / two;
+    }
+    guess
+}
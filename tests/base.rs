@@ -2,7 +2,7 @@ extern crate alloc;
 extern crate geohash;
 
 use fixed::types::I64F64;
-use geohash::GeoHash;
+use geohash::{Coordinate, GeoHash, GeohashError};
 use std::convert::TryFrom;
 
 #[test]
@@ -94,3 +94,158 @@ fn test_neighbor_wide() {
 	assert_eq!(ns.n, Geo4::try_from("9g3q").unwrap());
 	assert_eq!(ns.ne, Geo4::try_from("9g3w").unwrap());
 }
+
+#[test]
+fn test_cover_radius_caps_before_scanning() {
+	let lat = I64F64::from_num(37.8324f64);
+	let lon = I64F64::from_num(112.5584);
+
+	// A 5km radius at 9-character precision (a cell is ~4.7m across, per the
+	// standard geohash precision table, and ~3.8m wide in longitude at this
+	// latitude) needs around 7 million cells, so even a generous-looking cap
+	// should reject it up front.
+	let err = GeoHash::<9>::cover_radius(lat, lon, I64F64::from_num(5000), 100_000).unwrap_err();
+	assert!(matches!(err, GeohashError::BoundingBoxTooLarge));
+
+	// A 20m radius only needs a handful of rings at that same precision, so
+	// it succeeds under the same cap and actually covers the circle.
+	let cells = GeoHash::<9>::cover_radius(lat, lon, I64F64::from_num(20), 100_000).unwrap();
+	assert!(cells.contains(&GeoHash::<9>::try_from_params(lat, lon).unwrap()));
+	assert!(cells.len() > 9);
+}
+
+#[test]
+fn test_cover_bbox_caps_before_scanning() {
+	let min_lat = I64F64::from_num(30);
+	let min_lon = I64F64::from_num(110);
+	let max_lat = I64F64::from_num(31);
+	let max_lon = I64F64::from_num(111);
+
+	let err =
+		GeoHash::<8>::cover_bbox(min_lat, min_lon, max_lat, max_lon, 16).unwrap_err();
+	assert!(matches!(err, GeohashError::BoundingBoxTooLarge));
+
+	let cells =
+		GeoHash::<3>::cover_bbox(min_lat, min_lon, max_lat, max_lon, 1_000).unwrap();
+	assert!(cells.contains(&GeoHash::<3>::try_from_params(min_lat, min_lon).unwrap()));
+	assert!(cells.contains(&GeoHash::<3>::try_from_params(max_lat, max_lon).unwrap()));
+}
+
+#[test]
+fn test_within_radius_widens_when_radius_exceeds_cell_size() {
+	let lat = I64F64::from_num(37.8324f64);
+	let lon = I64F64::from_num(112.5584);
+
+	// At 9-character precision a cell is ~4.7m across (per the standard
+	// geohash precision table, not centimeters), so a 1m radius is well
+	// within the 3x3 neighborhood's coverage.
+	let fine = GeoHash::<9>::within_radius(lat, lon, I64F64::from_num(1), 1_000).unwrap();
+	assert!(fine.len() <= 9);
+
+	// At 5-character precision a cell is kilometers across, so a 50km radius
+	// needs more than the 3x3 neighborhood - this used to silently return
+	// the same 9 cells regardless of radius.
+	let coarse =
+		GeoHash::<5>::within_radius(lat, lon, I64F64::from_num(50_000), 100_000).unwrap();
+	assert!(coarse.len() > 9);
+}
+
+#[test]
+fn test_morton_round_trip() {
+	let gh = GeoHash::<9>::try_from("ww8p1r4t8").unwrap();
+
+	let bits = gh.try_to_u64().unwrap();
+	assert_eq!(GeoHash::<9>::from_interleaved(bits).unwrap(), gh);
+
+	let (min, max) = gh.interleaved_range().unwrap();
+	assert!(min < max);
+	assert_eq!(max - min, 1u64 << (64 - 5 * 9));
+
+	// A bit set above the `5 * LEN` range a code should occupy is rejected
+	// rather than silently truncated.
+	assert!(matches!(
+		GeoHash::<9>::from_interleaved(u64::MAX).unwrap_err(),
+		GeohashError::InvalidPrecision
+	));
+
+	// `5 * LEN` doesn't fit a u64 once LEN > 12.
+	let too_long = GeoHash::<13>::try_from_params(I64F64::from_num(0), I64F64::from_num(0)).unwrap();
+	assert!(matches!(
+		too_long.try_to_u64().unwrap_err(),
+		GeohashError::InvalidPrecision
+	));
+}
+
+#[test]
+fn test_haversine_and_equirectangular() {
+	let a = GeoHash::<9>::try_from("ww8p1r4t8").unwrap();
+	let b = GeoHash::<9>::try_from("ww8p1r4t9").unwrap();
+
+	let haversine_m = a.distance_to(&b).unwrap();
+	let equirect_m = a.equirectangular_to(&b).unwrap();
+	assert!(haversine_m < I64F64::from_num(100));
+	// Over a short distance the cheap approximation should track Haversine
+	// closely.
+	compare_within(haversine_m, equirect_m, I64F64::from_num(1));
+
+	// The CORDIC trig approximations leave sub-centimeter error even for
+	// identical points, so don't expect an exact 0.
+	compare_within(
+		a.distance_to(&a).unwrap(),
+		I64F64::from_num(0),
+		I64F64::from_num(1e-2),
+	);
+}
+
+#[test]
+fn test_geo_uri_round_trip() {
+	let gh = GeoHash::<9>::try_from_params(I64F64::from_num(37.8324f64), I64F64::from_num(112.5584))
+		.unwrap();
+
+	let uri = gh.to_geo_uri();
+	assert!(uri.starts_with("geo:"));
+	assert_eq!(GeoHash::<9>::try_from_geo_uri(&uri).unwrap(), gh);
+
+	assert!(GeoHash::<9>::try_from_geo_uri("geo:37.8324,112.5584;crs=wgs84").is_ok());
+	assert!(matches!(
+		GeoHash::<9>::try_from_geo_uri("geo:37.8324,112.5584;crs=nad83").unwrap_err(),
+		GeohashError::InvalidGeoUri
+	));
+	assert!(GeoHash::<9>::try_from_geo_uri("not-a-geo-uri").is_err());
+}
+
+#[test]
+fn test_coordinate() {
+	let lat = I64F64::from_num(37.8324f64);
+	let lon = I64F64::from_num(112.5584);
+	let c = Coordinate::new(lat, lon).unwrap();
+	assert_eq!(c.lat(), lat);
+	assert_eq!(c.lon(), lon);
+
+	assert!(Coordinate::new(I64F64::from_num(100), lon).is_err());
+	assert!(Coordinate::new(lat, I64F64::from_num(200)).is_err());
+
+	// The tuple `TryFrom` impls use GIS (lon, lat) order.
+	assert_eq!(Coordinate::try_from((lon, lat)).unwrap(), c);
+	assert_eq!(Coordinate::from_lat_lon(lat, lon).unwrap(), c);
+	assert_eq!(Coordinate::from_lon_lat(lon, lat).unwrap(), c);
+
+	let gh = GeoHash::<9>::try_from_coordinate(c).unwrap();
+	assert_eq!(gh, GeoHash::<9>::try_from_params(lat, lon).unwrap());
+
+	let diff = I64F64::from_num(1e-3);
+	let decoded = gh.as_coordinate().unwrap();
+	compare_within(decoded.lat(), lat, diff);
+	compare_within(decoded.lon(), lon, diff);
+}
+
+#[cfg(feature = "geojson")]
+#[test]
+fn test_to_geojson() {
+	let gh = GeoHash::<5>::try_from("9q60y").unwrap();
+	let json = gh.to_geojson().unwrap();
+	assert!(json.contains("\"type\":\"Feature\""));
+	assert!(json.contains("\"type\":\"Polygon\""));
+	assert!(json.contains("\"geohash\":\"9q60y\""));
+	assert!(json.contains("\"bbox\":["));
+}